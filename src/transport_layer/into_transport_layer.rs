@@ -1,12 +1,21 @@
+use ::anyhow::anyhow;
 use ::anyhow::Result;
 
 use super::TransportLayerBuilder;
 use crate::transport_layer::TransportLayer;
 
+mod insert_connect_info;
 mod into_make_service;
 mod into_make_service_with_connect_info;
+mod method_router;
+mod mock_http_duplex;
 mod router;
 mod serve;
+mod tower_service;
+
+pub(crate) use self::insert_connect_info::InsertConnectInfo;
+pub(crate) use self::mock_http_duplex::DuplexTransportLayer;
+pub use self::tower_service::TowerService;
 
 ///
 /// This exists to unify how to send mock or real messages to different services.
@@ -24,12 +33,31 @@ pub trait IntoTransportLayer: Sized {
         builder: TransportLayerBuilder,
     ) -> Result<Box<dyn TransportLayer>>;
 
-    fn into_mock_transport_layer(self) -> Result<Box<dyn TransportLayer>>;
+    fn into_mock_transport_layer(self, builder: TransportLayerBuilder) -> Result<Box<dyn TransportLayer>>;
 
-    fn into_default_transport(
+    /// The opt-in counterpart of [`into_mock_transport_layer`](Self::into_mock_transport_layer),
+    /// used for `Transport::MockHttpDuplex`. Unlike the plain mock transport,
+    /// which calls the service directly via `tower::Service::oneshot`, this
+    /// drives requests through a real HTTP/1 connection over an in-memory
+    /// duplex pipe - see [`DuplexTransportLayer`](super::DuplexTransportLayer).
+    ///
+    /// Implementations that can't produce a standalone service to hand to
+    /// the duplex transport (for example, one that has already bound itself
+    /// to a real connection) should return a descriptive error here, rather
+    /// than silently falling back to `into_mock_transport_layer`.
+    fn into_mock_duplex_transport_layer(
         self,
         _builder: TransportLayerBuilder,
     ) -> Result<Box<dyn TransportLayer>> {
-        self.into_mock_transport_layer()
+        Err(anyhow!(
+            "This `IntoTransportLayer` implementation does not support `Transport::MockHttpDuplex`. Use `Transport::MockHttp` instead."
+        ))
+    }
+
+    fn into_default_transport(
+        self,
+        builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        self.into_mock_transport_layer(builder)
     }
 }
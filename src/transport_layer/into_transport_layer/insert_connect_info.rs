@@ -0,0 +1,52 @@
+use ::axum::extract::connect_info::ConnectInfo;
+use ::axum::extract::Request;
+use ::axum::response::Response;
+use ::std::convert::Infallible;
+use ::std::net::SocketAddr;
+use ::std::task::Context;
+use ::std::task::Poll;
+use ::tower::Service;
+
+///
+/// Wraps a service so that every request it's given has a [`ConnectInfo`]
+/// extension inserted before being dispatched, mirroring what axum's real
+/// `IntoMakeServiceWithConnectInfo` does per-connection.
+///
+/// The mock transports never see a real connection to read a peer address
+/// from, so this is how they honour `TestServerConfig::default_connect_info`
+/// instead.
+///
+#[derive(Clone)]
+pub(crate) struct InsertConnectInfo<S> {
+    inner: S,
+    connect_info: SocketAddr,
+}
+
+impl<S> InsertConnectInfo<S> {
+    pub(crate) fn new(inner: S, connect_info: SocketAddr) -> Self {
+        Self {
+            inner,
+            connect_info,
+        }
+    }
+}
+
+impl<S> Service<Request> for InsertConnectInfo<S>
+where
+    S: Service<Request, Response = Response, Error = Infallible>,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request) -> Self::Future {
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(self.connect_info));
+        self.inner.call(request)
+    }
+}
@@ -0,0 +1,216 @@
+use ::anyhow::anyhow;
+use ::anyhow::Context;
+use ::anyhow::Result;
+use ::axum::extract::connect_info::IntoMakeServiceWithConnectInfo;
+use ::axum::routing::IntoMakeService;
+use ::axum::routing::MethodRouter;
+use ::std::net::SocketAddr;
+use ::std::net::TcpListener as StdTcpListener;
+use ::tokio::net::TcpListener;
+use ::tokio::spawn;
+use ::url::Url;
+
+use crate::internals::HttpTransportLayer;
+use crate::internals::MockTransportLayer;
+use crate::transport_layer::into_transport_layer::DuplexTransportLayer;
+use crate::transport_layer::into_transport_layer::InsertConnectInfo;
+use crate::transport_layer::IntoTransportLayer;
+use crate::transport_layer::TransportLayer;
+use crate::transport_layer::TransportLayerBuilder;
+
+impl IntoTransportLayer for MethodRouter<()> {
+    fn into_http_transport_layer(
+        self,
+        builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        let make_service: IntoMakeService<Self> = self.into_make_service();
+        make_service.into_http_transport_layer(builder)
+    }
+
+    fn into_mock_transport_layer(
+        self,
+        builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        let service = InsertConnectInfo::new(self, builder.mock_connect_info());
+
+        Ok(Box::new(MockTransportLayer::new(service)))
+    }
+
+    fn into_mock_duplex_transport_layer(
+        self,
+        builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        let service = InsertConnectInfo::new(self, builder.mock_connect_info());
+
+        Ok(Box::new(DuplexTransportLayer::new(service)))
+    }
+}
+
+/// Shared by both mock methods below, so the error text can't drift between them.
+const CANNOT_MOCK_WITH_CONNECT_INFO_ERROR: &str = "`IntoMakeServiceWithConnectInfo` cannot be mocked directly, as its inner service is only reachable through a real connection. Pass the bare `MethodRouter` and set `TestServerConfig::default_connect_info` instead.";
+
+impl IntoTransportLayer for IntoMakeServiceWithConnectInfo<MethodRouter<()>, SocketAddr> {
+    fn into_http_transport_layer(
+        self,
+        builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        let socket_addr = builder.socket_address()?;
+
+        let std_listener = StdTcpListener::bind(socket_addr)
+            .with_context(|| format!("Failed to bind to address {socket_addr}"))?;
+        std_listener.set_nonblocking(true)?;
+        let listener = TcpListener::from_std(std_listener)?;
+        let local_addr = listener.local_addr()?;
+
+        let server_handle = spawn(async move {
+            ::axum::serve(listener, self)
+                .await
+                .context("Failed to create ::axum::Server for TestServer")
+                .expect("Expect server to start serving");
+        });
+
+        let server_address = format!("http://{local_addr}");
+        let server_url: Url = server_address.parse()?;
+
+        Ok(Box::new(HttpTransportLayer::new(
+            server_handle,
+            None,
+            server_url,
+        )))
+    }
+
+    fn into_mock_transport_layer(
+        self,
+        _builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        // `IntoMakeServiceWithConnectInfo` only implements `Service<IncomingStream>`,
+        // so its inner service can't be pulled back out without a real
+        // connection to accept. Mock the bare `MethodRouter` instead, and set
+        // `TestServerConfig::default_connect_info` to exercise `ConnectInfo`
+        // extraction under a mock transport.
+        Err(anyhow!(CANNOT_MOCK_WITH_CONNECT_INFO_ERROR))
+    }
+
+    fn into_mock_duplex_transport_layer(
+        self,
+        _builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        Err(anyhow!(CANNOT_MOCK_WITH_CONNECT_INFO_ERROR))
+    }
+}
+
+#[cfg(test)]
+mod test_into_transport_layer_for_method_router {
+    use ::axum::routing::get;
+    use ::axum::routing::post;
+
+    use crate::TestServer;
+    use crate::TestServerConfig;
+    use crate::Transport;
+
+    async fn get_ping() -> &'static str {
+        "pong!"
+    }
+
+    async fn post_create() -> &'static str {
+        "created!"
+    }
+
+    #[tokio::test]
+    async fn it_should_create_and_test_a_standalone_method_router_over_http() {
+        let router = get(get_ping).post(post_create);
+
+        let config = TestServerConfig {
+            transport: Some(Transport::HttpRandomPort),
+            ..TestServerConfig::default()
+        };
+        let server = TestServer::new_with_config(router, config).expect("Should create test server");
+
+        server.get(&"/").await.assert_text(&"pong!");
+        server.post(&"/").await.assert_text(&"created!");
+    }
+
+    #[tokio::test]
+    async fn it_should_create_and_test_a_standalone_method_router_over_mock_http() {
+        let router = get(get_ping).post(post_create);
+
+        let config = TestServerConfig {
+            transport: Some(Transport::MockHttp),
+            ..TestServerConfig::default()
+        };
+        let server = TestServer::new_with_config(router, config).expect("Should create test server");
+
+        server.get(&"/").await.assert_text(&"pong!");
+        server.post(&"/").await.assert_text(&"created!");
+    }
+
+    #[tokio::test]
+    async fn it_should_create_and_test_a_standalone_method_router_over_mock_http_duplex() {
+        let router = get(get_ping).post(post_create);
+
+        let config = TestServerConfig {
+            transport: Some(Transport::MockHttpDuplex),
+            ..TestServerConfig::default()
+        };
+        let server = TestServer::new_with_config(router, config).expect("Should create test server");
+
+        server.get(&"/").await.assert_text(&"pong!");
+        server.post(&"/").await.assert_text(&"created!");
+    }
+}
+
+#[cfg(test)]
+mod test_into_mock_transport_layer_with_connect_info {
+    use ::axum::extract::connect_info::ConnectInfo;
+    use ::axum::routing::get;
+    use ::std::net::SocketAddr;
+
+    use crate::TestServer;
+    use crate::TestServerConfig;
+    use crate::Transport;
+
+    async fn get_client_ip(ConnectInfo(addr): ConnectInfo<SocketAddr>) -> String {
+        addr.to_string()
+    }
+
+    #[tokio::test]
+    async fn it_should_default_the_mock_connect_info_to_a_loopback_address() {
+        let router = get(get_client_ip);
+
+        let config = TestServerConfig {
+            transport: Some(Transport::MockHttp),
+            ..TestServerConfig::default()
+        };
+        let server = TestServer::new_with_config(router, config).expect("Should create test server");
+
+        server.get(&"/").await.assert_text(&"127.0.0.1:0");
+    }
+
+    #[tokio::test]
+    async fn it_should_use_the_configured_mock_connect_info() {
+        let router = get(get_client_ip);
+
+        let config = TestServerConfig {
+            transport: Some(Transport::MockHttp),
+            default_connect_info: Some("10.1.2.3:9000".parse().unwrap()),
+            ..TestServerConfig::default()
+        };
+        let server = TestServer::new_with_config(router, config).expect("Should create test server");
+
+        server.get(&"/").await.assert_text(&"10.1.2.3:9000");
+    }
+
+    #[tokio::test]
+    async fn it_should_use_the_configured_mock_connect_info_over_the_duplex_transport() {
+        let router = get(get_client_ip);
+
+        let config = TestServerConfig {
+            transport: Some(Transport::MockHttpDuplex),
+            default_connect_info: Some("10.1.2.3:9000".parse().unwrap()),
+            ..TestServerConfig::default()
+        };
+        let server = TestServer::new_with_config(router, config).expect("Should create test server");
+
+        server.get(&"/").await.assert_text(&"10.1.2.3:9000");
+    }
+}
@@ -0,0 +1,102 @@
+use ::anyhow::Context;
+use ::anyhow::Result;
+use ::axum::body::Body;
+use ::axum::extract::Request;
+use ::axum::response::Response;
+use ::hyper::service::service_fn;
+use ::hyper_util::rt::TokioIo;
+use ::std::convert::Infallible;
+use ::tokio::io::duplex;
+use ::tokio::spawn;
+use ::tower::Service;
+use ::tower::ServiceExt;
+
+use crate::transport_layer::TransportLayer;
+
+/// Big enough that a `TestRequest` body won't block on a full duplex buffer
+/// before the mock server has a chance to read it.
+const DUPLEX_BUFFER_SIZE: usize = 1024 * 1024;
+
+///
+/// A [`TransportLayer`] that drives every request through a genuine HTTP/1
+/// connection, running over an in-memory [`tokio::io::duplex`] pipe instead
+/// of a bound TCP socket.
+///
+/// Each request gets its own duplex pair: one half is handed to a fresh
+/// `hyper` server connection wrapping a clone of the service under test, and
+/// the other half is driven by a `hyper` client connection that sends the
+/// request through. This gives real header casing, chunked body handling,
+/// and `Content-Length` behaviour, which a plain `tower::Service::oneshot`
+/// call cannot reproduce, while still never touching the network. Handing
+/// out one connection per request, rather than reusing a single one, also
+/// means `tower` middleware that tracks state across clones (such as a
+/// concurrency limit) still sees genuinely concurrent in-flight requests,
+/// instead of having them serialized by a shared connection.
+///
+/// This is the mock target for `Transport::MockHttpDuplex`, used by the
+/// `IntoTransportLayer` implementations for `MethodRouter` and `TowerService`.
+/// The other implementations return an error for that transport.
+///
+pub(crate) struct DuplexTransportLayer<S> {
+    service: S,
+}
+
+impl<S> DuplexTransportLayer<S>
+where
+    S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + Sync + 'static,
+    S::Future: Send,
+{
+    pub(crate) fn new(service: S) -> Self {
+        Self { service }
+    }
+}
+
+#[::async_trait::async_trait]
+impl<S> TransportLayer for DuplexTransportLayer<S>
+where
+    S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + Sync + 'static,
+    S::Future: Send,
+{
+    async fn send(&self, request: ::http::Request<Body>) -> Result<Response<Body>> {
+        let (server_io, client_io) = duplex(DUPLEX_BUFFER_SIZE);
+        let service = self.service.clone();
+
+        spawn(async move {
+            // `hyper`'s server connection hands us a `Request<Incoming>`, but
+            // every `IntoTransportLayer` service speaks `Request<Body>` (the
+            // `axum::extract::Request` alias), so convert the incoming body
+            // the same way `axum::serve` does before dispatching, rather
+            // than handing the tower service to the connection directly.
+            let hyper_service = service_fn(move |request: ::http::Request<::hyper::body::Incoming>| {
+                let service = service.clone();
+                async move { service.oneshot(request.map(Body::new)).await }
+            });
+
+            ::hyper::server::conn::http1::Builder::new()
+                .serve_connection(TokioIo::new(server_io), hyper_service)
+                .await
+                .expect("Expect the mock duplex connection to serve successfully");
+        });
+
+        // The handshake and the request/response round trip all happen
+        // directly on this (already async) method, so there's no need to
+        // block the calling runtime to bridge sync and async code.
+        let (mut send_request, connection) =
+            ::hyper::client::conn::http1::handshake(TokioIo::new(client_io))
+                .await
+                .context("Failed to perform the HTTP/1 handshake over the mock duplex connection")?;
+
+        spawn(async move {
+            connection
+                .await
+                .expect("Expect the mock duplex connection to close cleanly");
+        });
+
+        let response = send_request
+            .send_request(request)
+            .await
+            .context("Failed to send request over the mock duplex connection")?;
+
+        Ok(response.map(Body::new))
+    }
+}
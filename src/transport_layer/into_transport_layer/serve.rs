@@ -44,10 +44,25 @@ where
         )))
     }
 
-    fn into_mock_transport_layer(self) -> Result<Box<dyn TransportLayer>> {
+    fn into_mock_transport_layer(
+        self,
+        _builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        // Unlike `Router` or `IntoMakeService`, a `Serve` has already been
+        // bound to its own listener by the time it reaches here, so there's
+        // no service left to hand to `DuplexTransportLayer` - it can only be
+        // driven by awaiting `self` against the connection it already owns.
         Err(anyhow!("`Serve` cannot be mocked, as it's underlying implementation requires a real connection. Set the `TestServerConfig` to run with a transport of `HttpIpPort`."))
     }
 
+    fn into_mock_duplex_transport_layer(
+        self,
+        _builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        // For the same reason as above, there's no service to hand to `DuplexTransportLayer`.
+        Err(anyhow!("`Serve` cannot be mocked, as its underlying implementation requires a real connection. Set the `TestServerConfig` to run with a transport of `HttpIpPort`."))
+    }
+
     fn into_default_transport(
         self,
         builder: TransportLayerBuilder,
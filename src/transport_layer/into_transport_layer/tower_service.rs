@@ -0,0 +1,186 @@
+use ::anyhow::Context;
+use ::anyhow::Result;
+use ::axum::extract::Request;
+use ::axum::response::Response;
+use ::std::convert::Infallible;
+use ::std::net::TcpListener as StdTcpListener;
+use ::tokio::net::TcpListener;
+use ::tokio::spawn;
+use ::tower::make::Shared;
+use ::tower::Service;
+use ::url::Url;
+
+use crate::internals::HttpTransportLayer;
+use crate::internals::MockTransportLayer;
+use crate::transport_layer::into_transport_layer::DuplexTransportLayer;
+use crate::transport_layer::into_transport_layer::InsertConnectInfo;
+use crate::transport_layer::IntoTransportLayer;
+use crate::transport_layer::TransportLayer;
+use crate::transport_layer::TransportLayerBuilder;
+
+///
+/// Adapts an arbitrary [`tower::Service`](::tower::Service) stack, such as a
+/// [`ServiceBuilder`](::tower::ServiceBuilder) of [`Layer`](::tower::Layer)s
+/// wrapping a leaf service, or a [`service_fn`](::tower::service_fn), so it
+/// can be driven directly by `TestServer`.
+///
+/// This is useful for testing middleware in isolation, without first having
+/// to embed it in a [`Router`](::axum::Router).
+///
+/// **Warning**, this type may change in a future release.
+///
+pub struct TowerService<S>(pub S);
+
+impl<S> IntoTransportLayer for TowerService<S>
+where
+    S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + Sync + 'static,
+    S::Future: Send,
+{
+    fn into_http_transport_layer(
+        self,
+        builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        let socket_addr = builder.socket_address()?;
+
+        let std_listener = StdTcpListener::bind(socket_addr)
+            .with_context(|| format!("Failed to bind to address {socket_addr}"))?;
+        std_listener.set_nonblocking(true)?;
+        let listener = TcpListener::from_std(std_listener)?;
+        let local_addr = listener.local_addr()?;
+
+        // `Shared` turns a plain `Service<Request>` into the `MakeService`
+        // shape that `axum::serve` expects, by cloning it for every
+        // connection - the same thing a leaf `Router` does implicitly.
+        let make_service = Shared::new(self.0);
+
+        let server_handle = spawn(async move {
+            ::axum::serve(listener, make_service)
+                .await
+                .context("Failed to create ::axum::Server for TestServer")
+                .expect("Expect server to start serving");
+        });
+
+        let server_address = format!("http://{local_addr}");
+        let server_url: Url = server_address.parse()?;
+
+        Ok(Box::new(HttpTransportLayer::new(
+            server_handle,
+            None,
+            server_url,
+        )))
+    }
+
+    fn into_mock_transport_layer(
+        self,
+        builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        let service = InsertConnectInfo::new(self.0, builder.mock_connect_info());
+
+        Ok(Box::new(MockTransportLayer::new(service)))
+    }
+
+    fn into_mock_duplex_transport_layer(
+        self,
+        builder: TransportLayerBuilder,
+    ) -> Result<Box<dyn TransportLayer>> {
+        let service = InsertConnectInfo::new(self.0, builder.mock_connect_info());
+
+        Ok(Box::new(DuplexTransportLayer::new(service)))
+    }
+}
+
+#[cfg(test)]
+mod test_into_transport_layer_for_tower_service {
+    use ::axum::extract::Request;
+    use ::axum::response::Response;
+    use ::std::convert::Infallible;
+    use ::tower::service_fn;
+    use ::tower::Layer;
+
+    use crate::TestServer;
+    use crate::TestServerConfig;
+    use crate::Transport;
+
+    use super::TowerService;
+
+    async fn handle(_req: Request) -> Result<Response, Infallible> {
+        Ok(Response::new("pong!".into()))
+    }
+
+    #[tokio::test]
+    async fn it_should_test_a_bare_service_fn_over_mock_http() {
+        let service = service_fn(handle);
+
+        let config = TestServerConfig {
+            transport: Some(Transport::MockHttp),
+            ..TestServerConfig::default()
+        };
+        let server = TestServer::new_with_config(TowerService(service), config)
+            .expect("Should create test server");
+
+        server.get(&"/").await.assert_text(&"pong!");
+    }
+
+    #[tokio::test]
+    async fn it_should_test_a_bare_service_fn_over_http() {
+        let service = service_fn(handle);
+
+        let config = TestServerConfig {
+            transport: Some(Transport::HttpRandomPort),
+            ..TestServerConfig::default()
+        };
+        let server = TestServer::new_with_config(TowerService(service), config)
+            .expect("Should create test server");
+
+        server.get(&"/").await.assert_text(&"pong!");
+    }
+
+    #[tokio::test]
+    async fn it_should_enforce_a_concurrency_limit_over_the_mock_duplex_transport() {
+        use ::std::sync::atomic::AtomicUsize;
+        use ::std::sync::atomic::Ordering;
+        use ::std::sync::Arc;
+        use ::std::time::Duration;
+        use ::tower::limit::ConcurrencyLimitLayer;
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+        let service = {
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+
+            service_fn(move |_req: Request| {
+                let in_flight = in_flight.clone();
+                let max_in_flight = max_in_flight.clone();
+
+                async move {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(current, Ordering::SeqCst);
+
+                    ::tokio::time::sleep(Duration::from_millis(50)).await;
+
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    Ok::<_, Infallible>(Response::new("pong!".into()))
+                }
+            })
+        };
+        let service = ConcurrencyLimitLayer::new(1).layer(service);
+
+        let config = TestServerConfig {
+            transport: Some(Transport::MockHttpDuplex),
+            ..TestServerConfig::default()
+        };
+        let server = TestServer::new_with_config(TowerService(service), config)
+            .expect("Should create test server");
+
+        let (first, second) = ::tokio::join!(server.get(&"/"), server.get(&"/"));
+        first.assert_text(&"pong!");
+        second.assert_text(&"pong!");
+
+        // The layer only allows one request through at a time, so even
+        // though both requests ran concurrently over their own duplex
+        // connection, at most one should ever have been in flight.
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 1);
+    }
+}